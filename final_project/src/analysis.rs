@@ -1,27 +1,29 @@
 // analysis.rs: This module is for network analysis functions including: degree distribution, average path length, and jaccard similarity for a graph
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use crate::network::Graph;
+use rayon::prelude::*;
 
 /// runs a BFS from the given start node and returns the shortest distance to each reachable node.
+/// ignores edge weights - only suitable for unweighted (or uniformly weighted) graphs. keyed by
+/// node id rather than indexed by raw id, since ids aren't guaranteed to be a dense 0..num_nodes()
+/// range (e.g. a graph with nodes {100, 200} has num_nodes() == 2).
 /// 'graph': the input graph as a reference to the graph struct; 'start': the starting node index
-/// returns a vector of shortest distances from `start` to every other node (usize::MAX if unreachable)
-pub fn bfs_shortest_paths(graph: &Graph, start: usize) -> Vec<usize> {
-    let mut visited = HashSet::new();  // track visited nodes
-    let mut distance = vec![usize::MAX; graph.num_nodes()];  // initialize all distances to 'infinity'
+/// returns a map of shortest distance from `start` to every reachable node (unreachable nodes are absent)
+pub fn bfs_shortest_paths(graph: &Graph, start: usize) -> HashMap<usize, usize> {
+    let mut distance: HashMap<usize, usize> = HashMap::new();  // also doubles as the visited set
     let mut queue = VecDeque::new();  // queue for bfs traversal
 
-    visited.insert(start);  // mark the start node as visited
-    distance[start] = 0;    // distance to itself is 0
+    distance.insert(start, 0);  // distance to itself is 0
     queue.push_back(start); // start bfs traversal from start node
 
     while let Some(current) = queue.pop_front() {
         if let Some(neighbors) = graph.adj_list.get(&current) {
-            for &neighbor in neighbors {
-                if !visited.contains(&neighbor) {
-                    visited.insert(neighbor);  // visit neighbor
-                    distance[neighbor] = distance[current] + 1;  // set neighbor's distance
-                    queue.push_back(neighbor);  
+            for &(neighbor, _) in neighbors {
+                if !distance.contains_key(&neighbor) {
+                    distance.insert(neighbor, distance[&current] + 1);  // set neighbor's distance
+                    queue.push_back(neighbor);
                 }
             }
         }
@@ -30,24 +32,101 @@ pub fn bfs_shortest_paths(graph: &Graph, start: usize) -> Vec<usize> {
     distance
 }
 
-/// computes the average shortest path length from a given starting node using bfs
-/// returns average distance to all reachable nodes as 'f64'
-pub fn average_shortest_path_length(graph: &Graph, start: usize) -> f64 {
-    let shortest_path_lengths = bfs_shortest_paths(graph, start);
-    let mut total = 0;
-    let mut count = 0;
+/// a single entry in dijkstra's priority queue, ordered so that `BinaryHeap` (a max-heap)
+/// pops the lowest-cost entry first
+#[derive(Copy, Clone, PartialEq)]
+struct HeapEntry {
+    cost: f64,
+    node: usize,
+}
+
+impl Eq for HeapEntry {}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal)  // reversed for a min-heap
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// computes shortest-path distances from `start` to every reachable node using dijkstra's
+/// algorithm: a binary-heap priority queue keyed on tentative distance, popping the minimum each
+/// time and skipping stale entries whose popped distance exceeds the best distance already recorded
+pub fn dijkstra_shortest_paths(graph: &Graph, start: usize) -> HashMap<usize, f64> {
+    let mut distance: HashMap<usize, f64> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    distance.insert(start, 0.0);
+    heap.push(HeapEntry { cost: 0.0, node: start });
+
+    while let Some(HeapEntry { cost, node }) = heap.pop() {
+        if cost > distance[&node] {
+            continue;  // stale entry - a shorter path to this node was already found
+        }
 
-    for &dist in &shortest_path_lengths {
-        if dist != usize::MAX && dist != 0 {
-            total += dist;  // acumulate total distance
-            count += 1;  // count reachable nodes (excluding self)
+        if let Some(neighbors) = graph.adj_list.get(&node) {
+            for &(neighbor, weight) in neighbors {
+                let new_cost = cost + weight;
+                if new_cost < *distance.get(&neighbor).unwrap_or(&f64::INFINITY) {
+                    distance.insert(neighbor, new_cost);
+                    heap.push(HeapEntry { cost: new_cost, node: neighbor });
+                }
+            }
         }
     }
 
-    if count == 0 {
-        0.0
+    distance
+}
+
+/// returns true when every edge in the graph has unit weight, meaning plain bfs gives the same
+/// shortest-path distances as dijkstra
+fn is_unweighted(graph: &Graph) -> bool {
+    graph.adj_list.values().all(|neighbors| neighbors.iter().all(|&(_, weight)| weight == 1.0))
+}
+
+/// computes the average shortest path length from a given starting node, dispatching to bfs for
+/// unit-weight graphs and dijkstra when any edge weight is not 1.0
+/// returns average distance to all reachable nodes as 'f64'
+pub fn average_shortest_path_length(graph: &Graph, start: usize) -> f64 {
+    if is_unweighted(graph) {
+        let shortest_path_lengths = bfs_shortest_paths(graph, start);
+        let mut total = 0;
+        let mut count = 0;
+
+        for (&node, &dist) in &shortest_path_lengths {
+            if node != start {
+                total += dist;  // acumulate total distance
+                count += 1;  // count reachable nodes (excluding self)
+            }
+        }
+
+        if count == 0 {
+            0.0
+        } else {
+            total as f64 / count as f64
+        }
     } else {
-        total as f64 / count as f64
+        let shortest_path_lengths = dijkstra_shortest_paths(graph, start);
+        let mut total = 0.0;
+        let mut count = 0;
+
+        for (&node, &dist) in &shortest_path_lengths {
+            if node != start {
+                total += dist;  // acumulate total distance
+                count += 1;  // count reachable nodes (excluding self)
+            }
+        }
+
+        if count == 0 {
+            0.0
+        } else {
+            total / count as f64
+        }
     }
 }
 
@@ -83,6 +162,79 @@ pub fn print_degree_distribution(graph: &Graph) {
     }
 }
 
+/// builds a deduplicated adjacency map (node -> set of distinct neighbor ids), stripping edge
+/// weights and repeated edges, so triangle counting doesn't have to re-check for duplicates
+fn deduplicated_adjacency(graph: &Graph) -> HashMap<usize, HashSet<usize>> {
+    graph
+        .adj_list
+        .iter()
+        .map(|(&node, neighbors)| (node, neighbors.iter().map(|&(n, _)| n).collect()))
+        .collect()
+}
+
+/// the local clustering coefficient of `node`: the fraction of pairs among its neighbors that are
+/// themselves connected, i.e. `2 * triangles / (deg * (deg - 1))`, or `0.0` when `deg < 2`
+fn local_clustering_coefficient_from(adjacency: &HashMap<usize, HashSet<usize>>, node: usize) -> f64 {
+    let neighbors = match adjacency.get(&node) {
+        Some(set) if set.len() >= 2 => set,
+        _ => return 0.0,
+    };
+
+    let degree = neighbors.len();
+    let mut triangles = 0;
+    for &a in neighbors {
+        if let Some(a_neighbors) = adjacency.get(&a) {
+            triangles += neighbors.iter().filter(|&&b| b > a && a_neighbors.contains(&b)).count();
+        }
+    }
+
+    2.0 * triangles as f64 / (degree as f64 * (degree as f64 - 1.0))
+}
+
+/// measures how tightly knit `node`'s friend circle is: the fraction of pairs of its friends who
+/// are also friends with each other. returns `0.0` when `node` has fewer than 2 friends.
+pub fn local_clustering_coefficient(graph: &Graph, node: usize) -> f64 {
+    let adjacency = deduplicated_adjacency(graph);
+    local_clustering_coefficient_from(&adjacency, node)
+}
+
+/// the graph-wide average of `local_clustering_coefficient` over every node
+pub fn average_clustering_coefficient(graph: &Graph) -> f64 {
+    let adjacency = deduplicated_adjacency(graph);
+    if adjacency.is_empty() {
+        return 0.0;
+    }
+
+    let total: f64 = adjacency
+        .keys()
+        .map(|&node| local_clustering_coefficient_from(&adjacency, node))
+        .sum();
+
+    total / adjacency.len() as f64
+}
+
+/// counts the total number of triangles in the graph. each node only checks pairs of neighbors
+/// with an id greater than its own (node-ordered enumeration), so every triangle `{x, y, z}` with
+/// `x < y < z` is counted exactly once, at node `x`.
+pub fn count_triangles(graph: &Graph) -> usize {
+    let adjacency = deduplicated_adjacency(graph);
+    let mut total = 0;
+
+    for (&node, neighbors) in &adjacency {
+        let higher_neighbors: Vec<usize> = neighbors.iter().copied().filter(|&n| n > node).collect();
+        for i in 0..higher_neighbors.len() {
+            for j in (i + 1)..higher_neighbors.len() {
+                let (a, b) = (higher_neighbors[i], higher_neighbors[j]);
+                if adjacency.get(&a).is_some_and(|set| set.contains(&b)) {
+                    total += 1;
+                }
+            }
+        }
+    }
+
+    total
+}
+
 /// computes the Jaccard similarity between two nodes based on their neighbirs
 /// returns the similarity score as an 'f64'
 fn jaccard_similarity(graph: &Graph, a: usize, b: usize) -> f64 {
@@ -96,8 +248,8 @@ fn jaccard_similarity(graph: &Graph, a: usize, b: usize) -> f64 {
         None => return 0.0,
     };
 
-    let set_a: HashSet<_> = neighbors_a.iter().copied().collect();  // convert neighbor list to set
-    let set_b: HashSet<_> = neighbors_b.iter().copied().collect();
+    let set_a: HashSet<_> = neighbors_a.iter().map(|&(node, _)| node).collect();  // convert neighbor list to set
+    let set_b: HashSet<_> = neighbors_b.iter().map(|&(node, _)| node).collect();
 
     let intersection: usize = set_a.intersection(&set_b).count();
     let union: usize = set_a.union(&set_b).count();
@@ -150,6 +302,347 @@ pub fn find_most_similar_pair(graph: &Graph) {
     );
 }
 
+/// greedily matches `a`'s neighbors to `b`'s neighbors, each round picking the highest-scoring
+/// unmatched pair under `similarity`, and returns the sum of matched scores. identical ids always
+/// match with weight 1.0, since a node is as similar to itself as it gets. the greedy matching is
+/// not guaranteed to reach the maximum possible sum over all matchings.
+fn best_neighbor_matching_sum(
+    neighbors_a: &[usize],
+    neighbors_b: &[usize],
+    similarity: &HashMap<(usize, usize), f64>,
+) -> f64 {
+    let weight = |x: usize, y: usize| -> f64 {
+        if x == y {
+            1.0
+        } else {
+            similarity.get(&(x, y)).copied().unwrap_or(0.0)
+        }
+    };
+
+    let mut pairs: Vec<(usize, usize, f64)> = neighbors_a
+        .iter()
+        .flat_map(|&x| neighbors_b.iter().map(move |&y| (x, y, weight(x, y))))
+        .collect();
+    pairs.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());  // highest-scoring pairs first
+
+    let mut used_a = HashSet::new();
+    let mut used_b = HashSet::new();
+    let mut total = 0.0;
+
+    for (x, y, score) in pairs {
+        if !used_a.contains(&x) && !used_b.contains(&y) {
+            used_a.insert(x);
+            used_b.insert(y);
+            total += score;
+        }
+    }
+
+    total
+}
+
+/// refines `S(a,b)` for every ordered pair drawn from `nodes`, starting from 1.0 everywhere: each
+/// round sets `S(a,b)` to the greedy neighbor matching sum between `a` and `b` (see
+/// `best_neighbor_matching_sum`) divided by `max(deg(a), deg(b))`, so identical structures converge
+/// toward 1.0 and mismatched sizes get penalized. stops early once a round changes every score by
+/// less than a small epsilon. `neighbor_cap`, if set, truncates each node's neighbor list first -
+/// needed to keep this affordable on hub nodes (see `find_top_structural_similarities`).
+fn structural_similarity_among(
+    graph: &Graph,
+    nodes: &[usize],
+    iterations: usize,
+    neighbor_cap: Option<usize>,
+) -> HashMap<(usize, usize), f64> {
+    const EPSILON: f64 = 1e-4;
+
+    let neighbor_lists: HashMap<usize, Vec<usize>> = nodes
+        .iter()
+        .map(|&node| {
+            let mut neighbors: Vec<usize> = graph
+                .adj_list
+                .get(&node)
+                .map(|list| list.iter().map(|&(n, _)| n).collect())
+                .unwrap_or_default();
+            if let Some(cap) = neighbor_cap {
+                neighbors.truncate(cap);
+            }
+            (node, neighbors)
+        })
+        .collect();
+
+    let mut similarity: HashMap<(usize, usize), f64> = HashMap::new();
+    for &a in nodes {
+        for &b in nodes {
+            if a != b {
+                similarity.insert((a, b), 1.0);
+            }
+        }
+    }
+
+    for _ in 0..iterations {
+        let mut next_similarity = similarity.clone();
+        let mut max_change = 0.0f64;
+
+        for &a in nodes {
+            for &b in nodes {
+                if a == b {
+                    continue;
+                }
+
+                let neighbors_a = &neighbor_lists[&a];
+                let neighbors_b = &neighbor_lists[&b];
+                let max_deg = neighbors_a.len().max(neighbors_b.len());
+
+                let new_score = if max_deg == 0 {
+                    1.0
+                } else {
+                    best_neighbor_matching_sum(neighbors_a, neighbors_b, &similarity) / max_deg as f64
+                };
+
+                max_change = max_change.max((new_score - similarity[&(a, b)]).abs());
+                next_similarity.insert((a, b), new_score);
+            }
+        }
+
+        similarity = next_similarity;
+        if max_change < EPSILON {
+            break;
+        }
+    }
+
+    similarity
+}
+
+/// exact structural similarity (no neighbor cap) for every ordered pair of nodes in the graph.
+/// O(n^2) pairs each refined by a matching every iteration, so only affordable on small graphs -
+/// `find_top_structural_similarities` below uses a bounded node set instead.
+pub fn structural_similarity(graph: &Graph, iterations: usize) -> HashMap<(usize, usize), f64> {
+    let nodes: Vec<usize> = graph.adj_list.keys().copied().collect();
+    structural_similarity_among(graph, &nodes, iterations, None)
+}
+
+/// candidate pool size for `find_top_structural_similarities` - keeps it fast on hub-heavy
+/// graphs like facebook_combined.txt
+const MAX_STRUCTURAL_CANDIDATES: usize = 10;
+
+/// how many of a node's real neighbors to sample when building the comparison node set
+const MAX_NEIGHBOR_SAMPLE: usize = 8;
+
+/// picks up to `max_candidates` nodes whose degree is closest to `target`'s - catches nodes with
+/// the same friend-circle shape even if they share no friends with `target`
+fn degree_bucket_pool(graph: &Graph, target: usize, max_candidates: usize) -> Vec<usize> {
+    let target_degree = graph.degree(target) as i64;
+
+    let mut candidates: Vec<usize> = graph
+        .adj_list
+        .keys()
+        .copied()
+        .filter(|&node| node != target)
+        .collect();
+
+    candidates.sort_by_key(|&node| (graph.degree(node) as i64 - target_degree).abs());
+    candidates.truncate(max_candidates);
+    candidates
+}
+
+/// expands `seeds` with up to `sample` of each seed's real neighbors, deduplicated, so the
+/// matching has actual neighbor ids to compare instead of just the seeds themselves
+fn expand_with_sampled_neighbors(graph: &Graph, seeds: &[usize], sample: usize) -> Vec<usize> {
+    let mut nodes: HashSet<usize> = seeds.iter().copied().collect();
+
+    for &seed in seeds {
+        if let Some(neighbors) = graph.adj_list.get(&seed) {
+            nodes.extend(neighbors.iter().take(sample).map(|&(n, _)| n));
+        }
+    }
+
+    nodes.into_iter().collect()
+}
+
+/// finds and prints the top-N nodes most structurally similar to a given node, analogous to
+/// `find_top_jaccard_similarities` but comparing neighborhood shape rather than direct overlap
+pub fn find_top_structural_similarities(graph: &Graph, target: usize, top_n: usize, iterations: usize) {
+    println!(
+        "\nTop {top_n} users most similar to User {target} (based on structural similarity, approximated over the {MAX_STRUCTURAL_CANDIDATES} closest-degree candidates):"
+    );
+
+    let mut candidates = degree_bucket_pool(graph, target, MAX_STRUCTURAL_CANDIDATES);
+    candidates.push(target);
+
+    let nodes = expand_with_sampled_neighbors(graph, &candidates, MAX_NEIGHBOR_SAMPLE);
+    let similarity = structural_similarity_among(graph, &nodes, iterations, Some(MAX_NEIGHBOR_SAMPLE));
+
+    let mut scores: Vec<(usize, f64)> = candidates
+        .iter()
+        .filter(|&&node| node != target)
+        .map(|&node| (node, similarity.get(&(target, node)).copied().unwrap_or(0.0)))
+        .collect();
+
+    scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+    for (node, score) in scores.iter().take(top_n) {
+        println!("User {:>4} has structural similarity {:.3}", node, score);
+    }
+}
+
+/// runs brandes' algorithm from a single source node and returns each node's dependency
+/// (contribution to betweenness centrality) accumulated from this source
+fn brandes_single_source(graph: &Graph, source: usize) -> HashMap<usize, f64> {
+    // keyed by node id rather than indexed by raw id, since ids aren't guaranteed to be a dense
+    // 0..num_nodes() range (e.g. a graph with nodes {0, 1, 2, 10, 11} has num_nodes() == 5)
+    let mut distance: HashMap<usize, i64> = HashMap::new();
+    let mut sigma: HashMap<usize, f64> = HashMap::new();
+    let mut predecessors: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut stack = Vec::new();                // nodes in the order they were dequeued
+    let mut queue = VecDeque::new();
+
+    distance.insert(source, 0);
+    sigma.insert(source, 1.0);
+    queue.push_back(source);
+
+    while let Some(v) = queue.pop_front() {
+        stack.push(v);
+        if let Some(neighbors) = graph.adj_list.get(&v) {
+            for &(w, _) in neighbors {
+                // w reached for the first time
+                if !distance.contains_key(&w) {
+                    distance.insert(w, distance[&v] + 1);
+                    queue.push_back(w);
+                }
+                // shortest path to w via v
+                if distance[&w] == distance[&v] + 1 {
+                    *sigma.entry(w).or_insert(0.0) += sigma[&v];
+                    predecessors.entry(w).or_default().push(v);
+                }
+            }
+        }
+    }
+
+    let mut delta: HashMap<usize, f64> = HashMap::new();
+    let mut centrality = HashMap::new();
+
+    // pop the stack in reverse bfs order, accumulating dependencies
+    while let Some(w) = stack.pop() {
+        let delta_w = delta.get(&w).copied().unwrap_or(0.0);
+        if let Some(preds) = predecessors.get(&w) {
+            for &v in preds {
+                *delta.entry(v).or_insert(0.0) += (sigma[&v] / sigma[&w]) * (1.0 + delta_w);
+            }
+        }
+        if w != source {
+            centrality.insert(w, delta_w);
+        }
+    }
+
+    centrality
+}
+
+/// computes betweenness centrality for every node using brandes' algorithm: for each source node,
+/// run a bfs that tracks shortest-path counts and predecessors, then accumulate dependencies by
+/// popping nodes in reverse bfs order. since the graph is undirected, every pair is processed from
+/// both endpoints, so the summed scores are halved at the end.
+/// when the graph has at least `parallel_threshold` nodes, the per-source pass is run in parallel with rayon
+pub fn betweenness_centrality(graph: &Graph, parallel_threshold: usize) -> HashMap<usize, f64> {
+    let nodes: Vec<usize> = graph.adj_list.keys().copied().collect();
+
+    let per_source: Vec<HashMap<usize, f64>> = if nodes.len() >= parallel_threshold {
+        nodes.par_iter().map(|&s| brandes_single_source(graph, s)).collect()
+    } else {
+        nodes.iter().map(|&s| brandes_single_source(graph, s)).collect()
+    };
+
+    let mut centrality: HashMap<usize, f64> = HashMap::new();
+    for partial in per_source {
+        for (node, value) in partial {
+            *centrality.entry(node).or_insert(0.0) += value;
+        }
+    }
+
+    for value in centrality.values_mut() {
+        *value /= 2.0;
+    }
+
+    centrality
+}
+
+/// a disjoint-set (union-find) structure over node ids, used to group nodes into
+/// connected components without repeated traversals
+struct UnionFind {
+    parent: HashMap<usize, usize>,
+    rank: HashMap<usize, usize>,
+}
+
+impl UnionFind {
+    fn new(nodes: impl Iterator<Item = usize>) -> Self {
+        let mut parent = HashMap::new();
+        let mut rank = HashMap::new();
+        for node in nodes {
+            parent.insert(node, node);  // every node starts as its own root
+            rank.insert(node, 0);
+        }
+        UnionFind { parent, rank }
+    }
+
+    /// finds the representative root of `node`, compressing the path along the way
+    fn find(&mut self, node: usize) -> usize {
+        let parent = self.parent[&node];
+        if parent != node {
+            let root = self.find(parent);
+            self.parent.insert(node, root);  // point directly at the root
+            root
+        } else {
+            node
+        }
+    }
+
+    /// merges the sets containing `a` and `b`, attaching the lower-rank root under the higher one
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return;
+        }
+
+        let rank_a = self.rank[&root_a];
+        let rank_b = self.rank[&root_b];
+        if rank_a < rank_b {
+            self.parent.insert(root_a, root_b);
+        } else if rank_a > rank_b {
+            self.parent.insert(root_b, root_a);
+        } else {
+            self.parent.insert(root_b, root_a);
+            self.rank.insert(root_a, rank_a + 1);
+        }
+    }
+}
+
+/// groups the graph's nodes into connected components using union-find: every node starts as its
+/// own root, then every edge unions its two endpoints (union-by-rank with path compression in `find`).
+/// nodes are then grouped by their final representative root.
+pub fn connected_components(graph: &Graph) -> Vec<Vec<usize>> {
+    let mut union_find = UnionFind::new(graph.adj_list.keys().copied());
+
+    for (&node, neighbors) in &graph.adj_list {
+        for &(neighbor, _) in neighbors {
+            union_find.union(node, neighbor);
+        }
+    }
+
+    let mut components: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &node in graph.adj_list.keys() {
+        let root = union_find.find(node);
+        components.entry(root).or_default().push(node);
+    }
+
+    components.into_values().collect()
+}
+
+/// returns the number of connected components and the size of the largest one
+pub fn component_stats(graph: &Graph) -> (usize, usize) {
+    let components = connected_components(graph);
+    let largest = components.iter().map(Vec::len).max().unwrap_or(0);
+    (components.len(), largest)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,9 +698,31 @@ mod tests {
 
         let distances = bfs_shortest_paths(&graph, 0);
 
-        assert_eq!(distances[0], 0);
-        assert_eq!(distances[1], 1);
-        assert_eq!(distances[2], 1);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&1], 1);
+        assert_eq!(distances[&2], 1);
+    }
+
+    #[test]
+    fn test_bfs_shortest_paths_with_sparse_node_ids() {
+        // only 2 nodes, but ids 100 and 200 - num_nodes() == 2 must not be mistaken for an upper
+        // bound on node id
+        let mut graph = Graph::new();
+        graph.add_edge(100, 200);
+
+        let distances = bfs_shortest_paths(&graph, 100);
+
+        assert_eq!(distances[&100], 0);
+        assert_eq!(distances[&200], 1);
+    }
+
+    #[test]
+    fn test_average_shortest_path_length_with_sparse_node_ids() {
+        let mut graph = Graph::new();
+        graph.add_edge(100, 200);
+
+        let avg = average_shortest_path_length(&graph, 100);
+        assert!((avg - 1.0).abs() < 1e-6);
     }
 
     #[test]
@@ -220,4 +735,240 @@ mod tests {
         let avg = average_shortest_path_length(&graph, 0);
         assert!((avg - 1.0).abs() < 1e-6);  // (1+1)/2 = 1.0
     }
+
+    #[test]
+    fn test_betweenness_centrality_path_graph() {
+        // 0 - 1 - 2: node 1 lies on the only shortest path between 0 and 2
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+
+        let centrality = betweenness_centrality(&graph, usize::MAX);
+
+        assert!((centrality.get(&1).copied().unwrap_or(0.0) - 1.0).abs() < 1e-6);
+        assert_eq!(centrality.get(&0).copied().unwrap_or(0.0), 0.0);
+        assert_eq!(centrality.get(&2).copied().unwrap_or(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_centrality_triangle_is_symmetric() {
+        // every node in a triangle sits on no one's *only* shortest path
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+
+        let centrality = betweenness_centrality(&graph, 1);
+
+        for node in 0..3 {
+            assert_eq!(centrality.get(&node).copied().unwrap_or(0.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_betweenness_centrality_with_sparse_node_ids() {
+        // node ids aren't a dense 0..num_nodes() range here (only 5 nodes, but ids up to 11) -
+        // betweenness_centrality must not assume otherwise
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(10, 11);
+
+        let centrality = betweenness_centrality(&graph, usize::MAX);
+
+        assert!((centrality.get(&1).copied().unwrap_or(0.0) - 1.0).abs() < 1e-6);
+        assert_eq!(centrality.get(&10).copied().unwrap_or(0.0), 0.0);
+        assert_eq!(centrality.get(&11).copied().unwrap_or(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_connected_components_two_clusters() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(10, 11);
+
+        let mut components = connected_components(&graph);
+        for component in &mut components {
+            component.sort();
+        }
+        components.sort_by_key(|component| component[0]);
+
+        assert_eq!(components, vec![vec![0, 1, 2], vec![10, 11]]);
+    }
+
+    #[test]
+    fn test_dijkstra_shortest_paths_weighted() {
+        // 0 -(5)- 1, 0 -(1)- 2 -(1)- 1: the 0->2->1 detour is cheaper than the direct edge
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 5.0);
+        graph.add_weighted_edge(0, 2, 1.0);
+        graph.add_weighted_edge(2, 1, 1.0);
+
+        let distances = dijkstra_shortest_paths(&graph, 0);
+
+        assert_eq!(distances[&0], 0.0);
+        assert_eq!(distances[&2], 1.0);
+        assert_eq!(distances[&1], 2.0);
+    }
+
+    #[test]
+    fn test_average_shortest_path_length_dispatches_to_dijkstra_when_weighted() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(0, 1, 5.0);
+        graph.add_weighted_edge(0, 2, 1.0);
+        graph.add_weighted_edge(2, 1, 1.0);
+
+        let avg = average_shortest_path_length(&graph, 0);
+        assert!((avg - 1.5).abs() < 1e-6);  // (1 + 2) / 2 = 1.5
+    }
+
+    #[test]
+    fn test_structural_similarity_identical_stars() {
+        // two disjoint stars of the same shape: 0 has leaves 1,2; 10 has leaves 11,12
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(10, 11);
+        graph.add_edge(10, 12);
+
+        let similarity = structural_similarity(&graph, 5);
+
+        assert!((similarity[&(0, 10)] - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_structural_similarity_among_scores_low_without_neighbors_in_node_set() {
+        // same disjoint stars as above, but `nodes` is just the two hubs - none of their leaves.
+        // the bipartite match inside can only score a leaf pair on identity (1 == 11? no), so every
+        // pair defaults to unrelated and the hubs end up looking nothing alike despite being
+        // structurally identical. this is the bug `expand_with_sampled_neighbors` exists to avoid.
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(10, 11);
+        graph.add_edge(10, 12);
+
+        let similarity = structural_similarity_among(&graph, &[0, 10], 5, None);
+
+        assert!(similarity[&(0, 10)] < 0.1);
+    }
+
+    #[test]
+    fn test_expand_with_sampled_neighbors_includes_real_neighbor_ids() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(10, 11);
+        graph.add_edge(10, 12);
+
+        let expanded = expand_with_sampled_neighbors(&graph, &[0, 10], 8);
+
+        for node in [0, 1, 2, 10, 11, 12] {
+            assert!(expanded.contains(&node), "expected {node} in expanded node set");
+        }
+    }
+
+    #[test]
+    fn test_structural_similarity_penalizes_size_mismatch() {
+        // 0 has one friend, 10 has three friends - structurally different neighborhoods
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(10, 11);
+        graph.add_edge(10, 12);
+        graph.add_edge(10, 13);
+
+        let similarity = structural_similarity(&graph, 5);
+
+        assert!(similarity[&(0, 10)] < 1.0);
+    }
+
+    #[test]
+    fn test_degree_bucket_pool_includes_nodes_with_no_shared_neighbors() {
+        // two disjoint stars of the same shape and degree: 0-{1,2} and 10-{11,12}. 10 shares no
+        // neighbor with 0 at all, but has the same degree, so a degree-based pool must still surface it
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(10, 11);
+        graph.add_edge(10, 12);
+
+        let candidates = degree_bucket_pool(&graph, 0, 100);
+
+        assert!(candidates.contains(&10));
+    }
+
+    #[test]
+    fn test_find_top_structural_similarities_pipeline_surfaces_matching_shape_with_no_shared_neighbors() {
+        // same disjoint-star setup as above: 0 and 10 share no neighbors, so jaccard similarity
+        // between them is 0, but structural similarity should still rank 10 highly for user 0.
+        // `max_candidates` of 1 means the degree-matched pool alone is just [10] - none of the
+        // leaves - so this only passes if the pipeline really does expand the pool with real
+        // neighbors (see `expand_with_sampled_neighbors`) before refining similarity over it,
+        // the way `find_top_structural_similarities` does.
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(10, 11);
+        graph.add_edge(10, 12);
+
+        let mut candidates = degree_bucket_pool(&graph, 0, 1);
+        candidates.push(0);
+        assert_eq!(candidates, vec![10, 0]);
+
+        let nodes = expand_with_sampled_neighbors(&graph, &candidates, 8);
+        let similarity = structural_similarity_among(&graph, &nodes, 5, Some(8));
+
+        assert!((similarity[&(0, 10)] - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_local_clustering_coefficient_triangle() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+
+        assert!((local_clustering_coefficient(&graph, 0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_local_clustering_coefficient_open_triad() {
+        // 0 is friends with 1 and 2, but 1 and 2 aren't friends with each other
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+
+        assert_eq!(local_clustering_coefficient(&graph, 0), 0.0);
+    }
+
+    #[test]
+    fn test_local_clustering_coefficient_single_friend() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+
+        assert_eq!(local_clustering_coefficient(&graph, 0), 0.0);
+    }
+
+    #[test]
+    fn test_count_triangles_and_average_clustering() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+        graph.add_edge(2, 3);  // 3 only connects to 2, no new triangle
+
+        assert_eq!(count_triangles(&graph), 1);
+        assert!(average_clustering_coefficient(&graph) > 0.0);
+    }
+
+    #[test]
+    fn test_component_stats_single_cluster() {
+        let mut graph = Graph::new();
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 0);
+
+        assert_eq!(component_stats(&graph), (1, 3));
+    }
 }
@@ -1,27 +1,188 @@
 // data_loader.rs: this module handles reading edge list file and converts it into a graph structure
 
 use std::fs::File;
-use std::io::{BufReader, BufRead};
+use std::io::{self, BufReader, BufRead};
 use crate::network::Graph;
 
-/// loads the graph from a facebook edge list file where each line contains two node ids
-/// 'path': file path to the input edge list
-/// returns a 'Graph' struct with all edges from the file added
-pub fn load_graph_from_file(path: &str) -> Graph {
-    let file = File::open(path).expect("failed to open file");
+/// the textual layout of an edge-list file
+pub enum EdgeFormat {
+    /// two (or three, for the weight) whitespace-separated columns per line, e.g. "1 2" or "1 2 0.5"
+    Whitespace,
+    /// two (or three) comma-separated columns per line, e.g. "1,2" or "1,2,0.5"
+    Csv,
+    /// first column is the source node, every column after it is one of its neighbors, all unit-weight
+    AdjacencyList,
+}
+
+/// a line that didn't match the expected shape for the chosen `EdgeFormat`, skipped instead of
+/// aborting the whole load
+#[derive(Debug, PartialEq)]
+pub struct MalformedLine {
+    pub line_number: usize,
+    pub content: String,
+}
+
+/// what a single parsed line contributes to the graph: either one or more edges, or - for an
+/// adjacency-list line with a source but no neighbor columns - a node with no edges at all
+enum ParsedLine {
+    Edges(Vec<(usize, usize, f64)>),
+    IsolatedNode(usize),
+}
+
+/// parses a single non-comment, non-blank line according to `format` (with weights, defaulting to
+/// 1.0 when the format or line has no weight column), or `None` if the line doesn't parse
+fn parse_edges(line: &str, format: &EdgeFormat) -> Option<ParsedLine> {
+    match format {
+        EdgeFormat::Whitespace => {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts.len() {
+                2 => Some(ParsedLine::Edges(vec![(parts[0].parse().ok()?, parts[1].parse().ok()?, 1.0)])),
+                3 => Some(ParsedLine::Edges(vec![(parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?)])),
+                _ => None,
+            }
+        }
+        EdgeFormat::Csv => {
+            let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+            match parts.len() {
+                2 => Some(ParsedLine::Edges(vec![(parts[0].parse().ok()?, parts[1].parse().ok()?, 1.0)])),
+                3 => Some(ParsedLine::Edges(vec![(parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?)])),
+                _ => None,
+            }
+        }
+        EdgeFormat::AdjacencyList => {
+            let mut parts = line.split_whitespace();
+            let source: usize = parts.next()?.parse().ok()?;
+            let neighbors: Vec<usize> = parts.map(|p| p.parse().ok()).collect::<Option<_>>()?;
+            if neighbors.is_empty() {
+                // a source with no neighbor columns is a legitimate way to represent an isolated
+                // node in adjacency-list format, not a malformed line
+                Some(ParsedLine::IsolatedNode(source))
+            } else {
+                Some(ParsedLine::Edges(neighbors.into_iter().map(|n| (source, n, 1.0)).collect()))
+            }
+        }
+    }
+}
+
+/// loads a graph from `path` in the given `format`, skipping blank lines and lines starting with
+/// `#` or `%` (common edge-list comment conventions). malformed rows are collected as errors
+/// rather than aborting the load, so a single bad line doesn't take down the rest of the file.
+/// returns the graph built from every line that did parse, plus the list of lines that didn't.
+pub fn load_graph_with_format(path: &str, format: EdgeFormat) -> io::Result<(Graph, Vec<MalformedLine>)> {
+    let file = File::open(path)?;
     let reader = BufReader::new(file);
     let mut graph = Graph::new();
+    let mut errors = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let trimmed = line.trim();
 
-    for line in reader.lines() {
-        if let Ok(edge) = line {
-            let parts: Vec<&str> = edge.trim().split_whitespace().collect();
-            if parts.len() == 2 {
-                let a = parts[0].parse::<usize>().unwrap();  // parse first node
-                let b = parts[1].parse::<usize>().unwrap();  // parse second node
-                graph.add_edge(a, b);  // add undirected edge to graph
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('%') {
+            continue;
+        }
+
+        match parse_edges(trimmed, &format) {
+            Some(ParsedLine::Edges(edges)) => {
+                for (a, b, weight) in edges {
+                    graph.add_weighted_edge(a, b, weight);
+                }
+            }
+            Some(ParsedLine::IsolatedNode(node)) => {
+                graph.adj_list.entry(node).or_default();  // register the node with no neighbors
             }
+            None => errors.push(MalformedLine {
+                line_number: index + 1,
+                content: line,
+            }),
         }
     }
 
+    Ok((graph, errors))
+}
+
+/// loads the graph from a facebook edge list file where each line contains two node ids and an
+/// optional third column giving the edge weight (defaults to 1.0 when the column is absent).
+/// 'path': file path to the input edge list
+/// returns a 'Graph' struct with all edges from the file added
+pub fn load_graph_from_file(path: &str) -> Graph {
+    let (graph, _malformed_lines) = load_graph_with_format(path, EdgeFormat::Whitespace)
+        .expect("failed to open file");
     graph
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// writes `contents` to a throwaway file under the system temp dir and returns its path
+    fn write_temp_file(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(name);
+        let mut file = File::create(&path).expect("failed to create temp file");
+        file.write_all(contents.as_bytes()).expect("failed to write temp file");
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_load_graph_with_format_skips_comments_and_blank_lines() {
+        let path = write_temp_file(
+            "data_loader_test_comments.txt",
+            "# a comment\n\n1 2\n% another comment\n2 3\n",
+        );
+
+        let (graph, errors) = load_graph_with_format(&path, EdgeFormat::Whitespace).unwrap();
+
+        assert_eq!(graph.degree(1), 1);
+        assert_eq!(graph.degree(2), 2);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_load_graph_with_format_collects_malformed_lines() {
+        let path = write_temp_file(
+            "data_loader_test_malformed.txt",
+            "1 2\nnot an edge\n2 3\n",
+        );
+
+        let (graph, errors) = load_graph_with_format(&path, EdgeFormat::Whitespace).unwrap();
+
+        assert_eq!(graph.degree(1), 1);  // valid lines still load despite the bad one
+        assert_eq!(graph.degree(3), 1);
+        assert_eq!(
+            errors,
+            vec![MalformedLine { line_number: 2, content: "not an edge".to_string() }]
+        );
+    }
+
+    #[test]
+    fn test_load_graph_with_format_csv() {
+        let path = write_temp_file("data_loader_test_csv.txt", "1,2\n2,3,2.5\n");
+
+        let (graph, errors) = load_graph_with_format(&path, EdgeFormat::Csv).unwrap();
+
+        assert_eq!(graph.degree(2), 2);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_load_graph_with_format_adjacency_list() {
+        let path = write_temp_file("data_loader_test_adjacency.txt", "1 2 3 4\n");
+
+        let (graph, errors) = load_graph_with_format(&path, EdgeFormat::AdjacencyList).unwrap();
+
+        assert_eq!(graph.degree(1), 3);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_load_graph_with_format_adjacency_list_registers_isolated_nodes() {
+        let path = write_temp_file("data_loader_test_adjacency_isolated.txt", "1 2 3\n5\n");
+
+        let (graph, errors) = load_graph_with_format(&path, EdgeFormat::AdjacencyList).unwrap();
+
+        assert!(graph.adj_list.contains_key(&5));  // node 5 is registered...
+        assert_eq!(graph.degree(5), 0);            // ...but has no neighbors
+        assert!(errors.is_empty());                // and isn't reported as a bad line
+    }
+}
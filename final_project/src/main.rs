@@ -4,7 +4,17 @@ use analysis::print_degree_distribution;
 use analysis::find_top_jaccard_similarities;
 use analysis::find_most_similar_pair;
 use data_loader::load_graph_from_file;
+use data_loader::load_graph_with_format;
+use data_loader::EdgeFormat;
 use analysis::average_shortest_path_length;
+use analysis::betweenness_centrality;
+use analysis::component_stats;
+use analysis::find_top_structural_similarities;
+use analysis::structural_similarity;
+use analysis::average_clustering_coefficient;
+use analysis::local_clustering_coefficient;
+use analysis::count_triangles;
+use network::Graph;
 
 mod data_loader;
 mod network;
@@ -17,6 +27,13 @@ fn main() {
     println!("Number of users (nodes): {}", graph.num_nodes());
     println!("User 0 has {} direct friends", graph.degree(0));
 
+    // checks whether the dataset is actually one friend cluster or several disconnected ones
+    let (component_count, largest_component_size) = component_stats(&graph);
+    println!(
+        "The network has {} connected component(s), the largest containing {} user(s)",
+        component_count, largest_component_size
+    );
+
     // compute & print average shortest path length from node 0
     let avg_path_length = average_shortest_path_length(&graph, 0);
     println!("On average, User 0 is {:.2} connections away from other users in the graph", avg_path_length);
@@ -24,10 +41,69 @@ fn main() {
     // prints degree distribution as ascii histogram made from '*'
     println!("\nFriendship degree distribution - number of users with X friends");
     print_degree_distribution(&graph);
-    
+
+    // measures how tightly knit friend circles are - how many of your friends are friends with each other
+    println!(
+        "\nThe graph has {} triangle(s), with an average local clustering coefficient of {:.3}",
+        count_triangles(&graph),
+        average_clustering_coefficient(&graph)
+    );
+    println!(
+        "User 0's own local clustering coefficient is {:.3}",
+        local_clustering_coefficient(&graph, 0)
+    );
+
     // finds and prints top 5 nodes most similar to node 0 using jacard similarity
     find_top_jaccard_similarities(&graph, 0, 5);
 
     // finds and prints the most similar node pair in the graph by jaccard similarity
     find_most_similar_pair(&graph);
-} 
+
+    // compares one-hop jaccard similarity against iterative structural similarity for User 0,
+    // which can surface users with no shared friends but identically-shaped friend circles
+    find_top_structural_similarities(&graph, 0, 5, 10);
+
+    // sanity check for the structural similarity algorithm itself: `structural_similarity` is the
+    // exact, whole-graph version `find_top_structural_similarities` approximates on facebook_combined.txt
+    // (too slow to run there directly), demonstrated here on a tiny pair of disjoint, identically-shaped
+    // friend circles that share no members at all
+    let mut toy_graph = Graph::new();
+    toy_graph.add_edge(0, 1);
+    toy_graph.add_edge(0, 2);
+    toy_graph.add_edge(10, 11);
+    toy_graph.add_edge(10, 12);
+    let toy_similarity = structural_similarity(&toy_graph, 5);
+    println!(
+        "\nSanity check: two disjoint users with identically-shaped friend circles have structural similarity {:.3}",
+        toy_similarity[&(0, 10)]
+    );
+
+    // ranks users by betweenness centrality (brandes' algorithm) - a better "influencer"
+    // metric than jaccard/degree since it rewards users who bridge otherwise separate friend groups
+    let mut centrality: Vec<(usize, f64)> = betweenness_centrality(&graph, 100)
+        .into_iter()
+        .collect();
+    centrality.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+    println!("\nTop 5 users by betweenness centrality (bridges between friend groups):");
+    for (node, score) in centrality.iter().take(5) {
+        println!("User {:>4} has betweenness centrality {:.2}", node, score);
+    }
+
+    // demonstrates the pluggable edge-list formats (`EdgeFormat::Csv` and `EdgeFormat::AdjacencyList`)
+    // on small hand-authored sample files, since facebook_combined.txt above is whitespace-formatted
+    let (csv_graph, _malformed) = load_graph_with_format("sample_network.csv", EdgeFormat::Csv)
+        .expect("failed to open sample_network.csv");
+    println!(
+        "\nCSV-format sample graph loaded: {} node(s)",
+        csv_graph.num_nodes()
+    );
+
+    let (adjacency_graph, _malformed) =
+        load_graph_with_format("sample_network.adj", EdgeFormat::AdjacencyList)
+            .expect("failed to open sample_network.adj");
+    println!(
+        "Adjacency-list-format sample graph loaded: {} node(s)",
+        adjacency_graph.num_nodes()
+    );
+}
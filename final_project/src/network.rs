@@ -2,9 +2,10 @@
 
 use std::collections::HashMap;
 
-/// represents an undirected graph using an adjacency list
+/// represents an undirected, weighted graph using an adjacency list.
+/// each neighbor entry is a (node id, edge weight) pair
 pub struct Graph {
-    pub adj_list: HashMap<usize, Vec<usize>>,  // maps each node to its list of neighbors
+    pub adj_list: HashMap<usize, Vec<(usize, f64)>>,  // maps each node to its list of (neighbor, weight)
 }
 
 impl Graph {
@@ -15,11 +16,17 @@ impl Graph {
         }
     }
 
-    /// adds an undirected edge between two nodes.
+    /// adds an undirected, unweighted edge between two nodes (weight defaults to 1.0).
     /// updates both nodes' adjacency lists
     pub fn add_edge(&mut self, u: usize, v: usize) {
-        self.adj_list.entry(u).or_default().push(v);  // add v to u's neighbor list
-        self.adj_list.entry(v).or_default().push(u);  // add u to v's neighbor list
+        self.add_weighted_edge(u, v, 1.0);
+    }
+
+    /// adds an undirected edge between two nodes with the given weight.
+    /// updates both nodes' adjacency lists
+    pub fn add_weighted_edge(&mut self, u: usize, v: usize, weight: f64) {
+        self.adj_list.entry(u).or_default().push((v, weight));  // add v to u's neighbor list
+        self.adj_list.entry(v).or_default().push((u, weight));  // add u to v's neighbor list
     }
 
     /// returns the num of neighbors for a given node
@@ -47,4 +54,22 @@ mod tests {
         assert_eq!(graph.degree(2), 1);  // node 2 connected to 1
         assert_eq!(graph.degree(3), 1);  // node 3 connected to 1
     }
+
+    #[test]
+    fn test_add_edge_defaults_to_unit_weight() {
+        let mut graph = Graph::new();
+        graph.add_edge(1, 2);
+
+        assert_eq!(graph.adj_list[&1], vec![(2, 1.0)]);
+        assert_eq!(graph.adj_list[&2], vec![(1, 1.0)]);
+    }
+
+    #[test]
+    fn test_add_weighted_edge() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge(1, 2, 2.5);
+
+        assert_eq!(graph.adj_list[&1], vec![(2, 2.5)]);
+        assert_eq!(graph.adj_list[&2], vec![(1, 2.5)]);
+    }
 }